@@ -0,0 +1,87 @@
+use crate::{template::Template, topic::Topic};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// A single entry in an entity's `availability` list: an MQTT topic to track
+/// online/offline state from, with its own optional payload overrides.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Availability<'a> {
+  /// The MQTT topic subscribed to receive availability (online/offline) updates.
+  #[serde(borrow)]
+  pub topic: Topic<'a>,
+
+  /// The payload that represents the available state.
+  /// Defaults to `"online"`
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_available: Option<Cow<'a, str>>,
+
+  /// The payload that represents the unavailable state.
+  /// Defaults to `"offline"`
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_not_available: Option<Cow<'a, str>>,
+
+  /// Defines a template to extract device’s availability from the topic. To determine
+  /// the devices’s availability result of this template will be compared to
+  /// `payload_available` and `payload_not_available`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub value_template: Option<Template<'a>>,
+}
+
+/// Controls how multiple `availability` entries are combined into one overall state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AvailabilityMode {
+  /// Entity is available when all `availability` topics report available.
+  All,
+  /// Entity is available when any `availability` topic reports available.
+  Any,
+  /// Entity takes the state of whichever `availability` topic last reported. Default.
+  Latest,
+}
+
+impl Default for AvailabilityMode {
+  fn default() -> Self {
+    Self::Latest
+  }
+}
+
+impl AvailabilityMode {
+  pub fn is_default(&self) -> bool {
+    matches!(self, Self::Latest)
+  }
+}
+
+/// Home Assistant rejects configs that set both the `availability` list and a singular
+/// `availability_topic` on the same entity — only one form may be used at a time.
+pub(crate) fn is_ambiguous(availability: &[Availability], availability_topic: Option<&Topic>) -> bool {
+  !availability.is_empty() && availability_topic.is_some()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn neither_set_is_not_ambiguous() {
+    assert!(!is_ambiguous(&[], None));
+  }
+
+  #[test]
+  fn availability_topic_alone_is_not_ambiguous() {
+    let topic = Topic::from("device/availability");
+    assert!(!is_ambiguous(&[], Some(&topic)));
+  }
+
+  #[test]
+  fn availability_list_alone_is_not_ambiguous() {
+    let availability = [Availability { topic: "device/availability".into(), ..Availability::default() }];
+    assert!(!is_ambiguous(&availability, None));
+  }
+
+  #[test]
+  fn both_set_is_ambiguous() {
+    let availability = [Availability { topic: "device/availability".into(), ..Availability::default() }];
+    let topic = Topic::from("device/other_availability");
+    assert!(is_ambiguous(&availability, Some(&topic)));
+  }
+}
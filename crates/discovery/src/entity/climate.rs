@@ -0,0 +1,103 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The mqtt climate platform lets you control your MQTT enabled HVAC devices.
+///
+/// See: <https://www.home-assistant.io/integrations/climate.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Climate<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// A template to render the value received on the `action_topic` with.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub action_template: Option<Template<'a>>,
+
+  /// The MQTT topic to subscribe for changes of the current action.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub action_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic to subscribe for changes of the current temperature.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub current_temperature_topic: Option<Topic<'a>>,
+
+  /// A template to render the value received on the `current_temperature_topic` with.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub current_temperature_template: Option<Template<'a>>,
+
+  /// The MQTT topic to publish commands to change the HVAC mode.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub mode_command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic to subscribe for changes of the HVAC mode.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub mode_state_topic: Option<Topic<'a>>,
+
+  /// A template to extract the HVAC mode from the `mode_state_topic`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub mode_state_template: Option<Template<'a>>,
+
+  /// A list of supported modes. Needs to be a subset of the default mode list.
+  #[serde(borrow, default, skip_serializing_if = "<[Cow<str>]>::is_empty")]
+  pub modes: Cow<'a, [Cow<'a, str>]>,
+
+  /// The maximum temperature that can be set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub max_temp: Option<f32>,
+
+  /// The minimum temperature that can be set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub min_temp: Option<f32>,
+
+  /// Flag that defines if the climate works in optimistic mode. Defaults to `true`
+  /// if none of `mode_state_topic`, `temperature_state_topic` or
+  /// `current_temperature_topic` is set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub optimistic: Option<bool>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The MQTT topic to publish commands to change the target temperature.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub temperature_command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic to subscribe for changes in the target temperature.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub temperature_state_topic: Option<Topic<'a>>,
+
+  /// A template to extract the target temperature from the `temperature_state_topic`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub temperature_state_template: Option<Template<'a>>,
+
+  /// Step size for the target temperature.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub temp_step: Option<f32>,
+}
+
+impl<'a> Validate for Climate<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with_opt(&self.action_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.action_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.current_temperature_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.current_temperature_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.mode_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.mode_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.mode_state_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.temperature_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.temperature_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.temperature_state_template, EntityInvalidity::Template)
+      .into()
+  }
+}
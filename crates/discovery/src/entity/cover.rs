@@ -0,0 +1,128 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  device_class::DeviceClass, payload::Payload, template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+
+/// The mqtt cover platform lets you control an MQTT cover (such as blinds, a roller shutter
+/// or a garage door).
+///
+/// See: <https://www.home-assistant.io/integrations/cover.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cover<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// The MQTT topic to publish commands to control the cover.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_topic: Option<Topic<'a>>,
+
+  /// Sets the [class][device_class] of the device, changing the device state and icon shown.
+  ///
+  /// [device_class]: https://www.home-assistant.io/integrations/cover/#device-class
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub device_class: Option<DeviceClass>,
+
+  /// The command payload sent to `command_topic` to close the cover.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_close: Option<Payload<'a>>,
+
+  /// The command payload sent to `command_topic` to open the cover.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_open: Option<Payload<'a>>,
+
+  /// The command payload sent to `command_topic` to stop the cover.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_stop: Option<Payload<'a>>,
+
+  /// Number which represents the closed position.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub position_closed: Option<i32>,
+
+  /// Number which represents the open position.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub position_open: Option<i32>,
+
+  /// Defines a template to extract the payload for the `position_topic`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub position_template: Option<Template<'a>>,
+
+  /// The MQTT topic subscribed to receive cover position updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub position_topic: Option<Topic<'a>>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The MQTT topic to publish commands to move the cover to a specific position.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub set_position_topic: Option<Topic<'a>>,
+
+  /// Defines a template to define the position to be sent to `set_position_topic`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub set_position_template: Option<Template<'a>>,
+
+  /// The payload received on `state_topic` that represents the closed state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_closed: Option<Payload<'a>>,
+
+  /// The payload received on `state_topic` that represents the open state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_open: Option<Payload<'a>>,
+
+  /// The MQTT topic subscribed to receive cover state messages.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic to publish commands to control the cover tilt.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub tilt_command_topic: Option<Topic<'a>>,
+
+  /// The maximum tilt value.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tilt_max: Option<i32>,
+
+  /// The minimum tilt value.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tilt_min: Option<i32>,
+
+  /// Defines a template to extract the payload for the `tilt_status_topic`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub tilt_status_template: Option<Template<'a>>,
+
+  /// The MQTT topic subscribed to receive tilt status updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub tilt_status_topic: Option<Topic<'a>>,
+
+  /// Defines a template to extract the value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub value_template: Option<Template<'a>>,
+}
+
+impl<'a> Validate for Cover<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with_opt(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.payload_open, EntityInvalidity::Payload)
+      .validate_with_opt(&self.payload_close, EntityInvalidity::Payload)
+      .validate_with_opt(&self.payload_stop, EntityInvalidity::Payload)
+      .validate_with_opt(&self.position_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.position_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.set_position_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.set_position_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.state_open, EntityInvalidity::Payload)
+      .validate_with_opt(&self.state_closed, EntityInvalidity::Payload)
+      .validate_with_opt(&self.tilt_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.tilt_status_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.tilt_status_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.value_template, EntityInvalidity::Template)
+      .into()
+  }
+}
@@ -0,0 +1,114 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  payload::Payload, template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The mqtt fan platform lets you control your MQTT enabled fans.
+///
+/// See: <https://www.home-assistant.io/integrations/fan.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fan<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// The MQTT topic to publish commands to change the fan state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic to publish commands to change the fan direction.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub direction_command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic subscribed to receive direction state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub direction_state_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic to publish commands to change the fan oscillation.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub oscillation_command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic subscribed to receive oscillation state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub oscillation_state_topic: Option<Topic<'a>>,
+
+  /// Flag that defines if the fan works in optimistic mode. Defaults to `true`
+  /// if no `state_topic` is defined.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub optimistic: Option<bool>,
+
+  /// The payload that represents the stop state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_off: Option<Payload<'a>>,
+
+  /// The payload that represents the running state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_on: Option<Payload<'a>>,
+
+  /// The MQTT topic to publish commands to change the fan speed percentage.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub percentage_command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic subscribed to receive fan speed percentage updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub percentage_state_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic to publish commands to change the preset mode.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub preset_mode_command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic subscribed to receive preset mode updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub preset_mode_state_topic: Option<Topic<'a>>,
+
+  /// List of preset modes this fan is capable of running at.
+  #[serde(borrow, default, skip_serializing_if = "<[Cow<str>]>::is_empty")]
+  pub preset_modes: Cow<'a, [Cow<'a, str>]>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The maximum of the numeric output range that `percentage_command_topic` accepts.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub speed_range_max: Option<u32>,
+
+  /// The minimum of the numeric output range that `percentage_command_topic` accepts
+  /// (off not included, so this value - `1` represents 0%).
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub speed_range_min: Option<u32>,
+
+  /// The MQTT topic subscribed to receive state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_topic: Option<Topic<'a>>,
+
+  /// Defines a template to extract the state value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_value_template: Option<Template<'a>>,
+}
+
+impl<'a> Validate for Fan<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with_opt(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.payload_on, EntityInvalidity::Payload)
+      .validate_with_opt(&self.payload_off, EntityInvalidity::Payload)
+      .validate_with_opt(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.state_value_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.percentage_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.percentage_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.preset_mode_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.preset_mode_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.oscillation_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.oscillation_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.direction_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.direction_state_topic, EntityInvalidity::Topic)
+      .into()
+  }
+}
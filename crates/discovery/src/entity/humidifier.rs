@@ -0,0 +1,114 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  device_class::DeviceClass, payload::Payload, template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The mqtt humidifier platform lets you control your MQTT enabled humidifiers.
+///
+/// See: <https://www.home-assistant.io/integrations/humidifier.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Humidifier<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// The MQTT topic to publish commands to change the humidifier state.
+  #[serde(borrow)]
+  pub command_topic: Topic<'a>,
+
+  /// The [type/class][device_class] of the humidifier.
+  ///
+  /// [device_class]: https://www.home-assistant.io/integrations/humidifier/#device-class
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub device_class: Option<DeviceClass>,
+
+  /// The maximum target humidity percentage that can be set. Defaults to `100`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub max_humidity: Option<f32>,
+  /// The minimum target humidity percentage that can be set. Defaults to `0`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub min_humidity: Option<f32>,
+
+  /// List of supported modes, e.g. `"normal"`, `"eco"`, `"away"`. Not all
+  /// humidifiers support modes.
+  #[serde(borrow, default, skip_serializing_if = "<[Cow<str>]>::is_empty")]
+  pub modes: Cow<'a, [Cow<'a, str>]>,
+  /// The MQTT topic to publish commands to change the mode.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub mode_command_topic: Option<Topic<'a>>,
+  /// The MQTT topic subscribed to receive the mode.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub mode_state_topic: Option<Topic<'a>>,
+  /// Defines a template to extract the mode.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub mode_state_template: Option<Template<'a>>,
+
+  /// Flag that defines if the humidifier works in optimistic mode. Defaults to `true`
+  /// if no `state_topic` is defined, else `false`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub optimistic: Option<bool>,
+
+  /// The payload that represents the off state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_off: Option<Payload<'a>>,
+  /// The payload that represents the on state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_on: Option<Payload<'a>>,
+  /// The payload sent to `target_humidity_command_topic` to reset the target humidity.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_reset_humidity: Option<Payload<'a>>,
+  /// The payload sent to `mode_command_topic` to reset the mode.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_reset_mode: Option<Payload<'a>>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The MQTT topic subscribed to receive state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_topic: Option<Topic<'a>>,
+  /// Defines a template to extract the state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_value_template: Option<Template<'a>>,
+
+  /// The MQTT topic to publish commands to change the target humidity.
+  #[serde(borrow)]
+  pub target_humidity_command_topic: Topic<'a>,
+  /// Defines a template to generate the target humidity command payload.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub target_humidity_command_template: Option<Template<'a>>,
+  /// The MQTT topic subscribed to receive the target humidity.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub target_humidity_state_topic: Option<Topic<'a>>,
+  /// Defines a template to extract the target humidity.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub target_humidity_state_template: Option<Template<'a>>,
+}
+
+impl<'a> Validate for Humidifier<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.mode_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.mode_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.mode_state_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.payload_on, EntityInvalidity::Payload)
+      .validate_with_opt(&self.payload_off, EntityInvalidity::Payload)
+      .validate_with_opt(&self.payload_reset_humidity, EntityInvalidity::Payload)
+      .validate_with_opt(&self.payload_reset_mode, EntityInvalidity::Payload)
+      .validate_with_opt(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.state_value_template, EntityInvalidity::Template)
+      .validate_with(&self.target_humidity_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.target_humidity_command_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.target_humidity_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.target_humidity_state_template, EntityInvalidity::Template)
+      .into()
+  }
+}
@@ -0,0 +1,141 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  payload::Payload, template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The mqtt light platform lets you control your MQTT enabled lights.
+///
+/// See: <https://www.home-assistant.io/integrations/light.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Light<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// Defines the maximum brightness value.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub brightness_scale: Option<u32>,
+
+  /// The MQTT topic to publish commands to change the brightness.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub brightness_command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic subscribed to receive brightness state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub brightness_state_topic: Option<Topic<'a>>,
+
+  /// Defines a template to extract the brightness value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub brightness_value_template: Option<Template<'a>>,
+
+  /// The MQTT topic to publish commands to change the light's color temperature.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub color_temp_command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic subscribed to receive color temperature state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub color_temp_state_topic: Option<Topic<'a>>,
+
+  /// Defines a template to extract the color temperature value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub color_temp_value_template: Option<Template<'a>>,
+
+  /// The MQTT topic to publish commands to change the state of the light.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic to publish commands to change the effect.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub effect_command_topic: Option<Topic<'a>>,
+
+  /// The list of effects the light supports.
+  #[serde(borrow, default, skip_serializing_if = "<[Cow<str>]>::is_empty")]
+  pub effect_list: Cow<'a, [Cow<'a, str>]>,
+
+  /// The MQTT topic subscribed to receive effect state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub effect_state_topic: Option<Topic<'a>>,
+
+  /// Defines a template to extract the effect value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub effect_value_template: Option<Template<'a>>,
+
+  /// The maximum color temperature in mireds.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub max_mireds: Option<u32>,
+
+  /// The minimum color temperature in mireds.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub min_mireds: Option<u32>,
+
+  /// Flag that defines if the light works in optimistic mode. Defaults to `true`
+  /// if no `state_topic` is defined.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub optimistic: Option<bool>,
+
+  /// The payload that represents the off state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_off: Option<Payload<'a>>,
+
+  /// The payload that represents the on state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_on: Option<Payload<'a>>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The MQTT topic to publish commands to change the RGB color value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub rgb_command_topic: Option<Topic<'a>>,
+
+  /// The MQTT topic subscribed to receive RGB state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub rgb_state_topic: Option<Topic<'a>>,
+
+  /// Defines a template to extract the RGB value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub rgb_value_template: Option<Template<'a>>,
+
+  /// The schema to use: `default` for the original schema or `json` for the JSON schema.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub schema: Option<Cow<'a, str>>,
+
+  /// The MQTT topic subscribed to receive state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_topic: Option<Topic<'a>>,
+
+  /// Defines a template to extract the state value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_value_template: Option<Template<'a>>,
+}
+
+impl<'a> Validate for Light<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with_opt(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.payload_on, EntityInvalidity::Payload)
+      .validate_with_opt(&self.payload_off, EntityInvalidity::Payload)
+      .validate_with_opt(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.state_value_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.brightness_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.brightness_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.brightness_value_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.color_temp_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.color_temp_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.color_temp_value_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.rgb_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.rgb_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.rgb_value_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.effect_command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.effect_state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.effect_value_template, EntityInvalidity::Template)
+      .into()
+  }
+}
@@ -0,0 +1,70 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  payload::Payload, template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+
+/// The mqtt lock platform lets you control your MQTT enabled locks.
+///
+/// See: <https://www.home-assistant.io/integrations/lock.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lock<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// The MQTT topic to publish commands to change the lock state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_topic: Option<Topic<'a>>,
+
+  /// Flag that defines if the lock works in optimistic mode. Defaults to `true`
+  /// if no `state_topic` is defined.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub optimistic: Option<bool>,
+
+  /// The payload sent to `command_topic` to lock the device.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_lock: Option<Payload<'a>>,
+
+  /// The payload sent to `command_topic` to unlock the device.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_unlock: Option<Payload<'a>>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The payload received that represents the locked state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_locked: Option<Payload<'a>>,
+
+  /// The MQTT topic subscribed to receive lock state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_topic: Option<Topic<'a>>,
+
+  /// The payload received that represents the unlocked state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_unlocked: Option<Payload<'a>>,
+
+  /// Defines a template to extract the state value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub value_template: Option<Template<'a>>,
+}
+
+impl<'a> Validate for Lock<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with_opt(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.payload_lock, EntityInvalidity::Payload)
+      .validate_with_opt(&self.payload_unlock, EntityInvalidity::Payload)
+      .validate_with_opt(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.state_locked, EntityInvalidity::Payload)
+      .validate_with_opt(&self.state_unlocked, EntityInvalidity::Payload)
+      .validate_with_opt(&self.value_template, EntityInvalidity::Template)
+      .into()
+  }
+}
@@ -0,0 +1,164 @@
+use crate::{
+  availability::{self, Availability, AvailabilityMode}, device::Device, device_class::DeviceClass,
+  entity_category::EntityCategory, icon::Icon, name::Name, payload::Payload, qos::MqttQoS,
+  template::Template, topic::Topic, unique_id::UniqueId, exts::ValidateContextExt,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+pub mod button;
+pub mod climate;
+pub mod cover;
+pub mod fan;
+pub mod humidifier;
+pub mod light;
+pub mod lock;
+pub mod notify;
+pub mod number;
+pub mod select;
+pub mod sensor;
+pub mod siren;
+pub mod switch;
+pub mod text;
+pub mod update;
+
+pub use button::Button;
+pub use climate::Climate;
+pub use cover::Cover;
+pub use fan::Fan;
+pub use humidifier::Humidifier;
+pub use light::Light;
+pub use lock::Lock;
+pub use notify::Notify;
+pub use number::Number;
+pub use select::Select;
+pub use sensor::Sensor;
+pub use siren::Siren;
+pub use switch::Switch;
+pub use text::Text;
+pub use update::Update;
+
+/// Fields shared by (almost) every MQTT discovery entity: availability tracking,
+/// device registry linkage, and the handful of generic presentation fields HA
+/// exposes across all platforms. Platforms that predate this type (like [`Sensor`])
+/// still carry their own copy of these fields; newer platforms flatten this instead.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entity<'a> {
+  /// A list of MQTT topics subscribed to receive availability (online/offline) updates.
+  /// Must not be used together with `availability_topic`.
+  #[serde(borrow, default, skip_serializing_if = "<[Availability]>::is_empty")]
+  pub availability: Cow<'a, [Availability<'a>]>,
+
+  /// When `availability` is configured, this controls the conditions needed
+  /// to set the entity to `available`.
+  #[serde(default, skip_serializing_if = "AvailabilityMode::is_default")]
+  pub availability_mode: AvailabilityMode,
+
+  /// Defines a template to extract device’s availability from the `availability_topic`.
+  /// To determine the devices’s availability result of this template will be compared
+  /// to `payload_available` and `payload_not_available`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub availability_template: Option<Template<'a>>,
+
+  /// The MQTT topic subscribed to receive availability (online/offline) updates.
+  /// Must not be used together with `availability`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub availability_topic: Option<Topic<'a>>,
+
+  /// Information about the device this entity is a part of to tie it into the device registry.
+  /// Only works through MQTT discovery and when `unique_id` is set.
+  /// At least one of identifiers or connections must be present to identify the device.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub device: Option<Device<'a>>,
+
+  /// The encoding of the payloads received and published messages will be
+  /// published with. Set to `""` to disable decoding of incoming payloads as
+  /// UTF-8, passing them through as raw/unencoded bytes instead. Defaults to `"utf-8"`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub encoding: Option<Cow<'a, str>>,
+
+  /// Flag which defines if the entity should be enabled when first added.
+  /// Defaults to `true`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub enabled_by_default: Option<bool>,
+
+  /// The [category] of the entity.
+  ///
+  /// [category]: https://developers.home-assistant.io/docs/core/entity#generic-properties
+  #[serde(default, skip_serializing_if = "EntityCategory::is_none")]
+  pub entity_category: EntityCategory,
+
+  /// [Icon][icon] for the entity.
+  ///
+  /// [icon]: https://www.home-assistant.io/docs/configuration/customizing-devices/#icon
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub icon: Option<Icon<'a>>,
+
+  /// Defines a [template][template] to extract the JSON dictionary from messages received
+  /// on the `json_attributes_topic`.
+  ///
+  /// [template]: https://www.home-assistant.io/docs/configuration/templating/#processing-incoming-data
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub json_attributes_template: Option<Template<'a>>,
+
+  /// The MQTT topic subscribed to receive a JSON dictionary payload and then set as entity
+  /// attributes.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub json_attributes_topic: Option<Topic<'a>>,
+
+  /// The name of the entity.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<Name<'a>>,
+
+  /// Used instead of `name` for automatic generation of `entity_id`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub object_id: Option<Cow<'a, str>>,
+
+  /// The payload that represents the available state.
+  /// Defaults to `"online"`
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_available: Option<Cow<'a, str>>,
+
+  /// The payload that represents the unavailable state.
+  /// Defaults to `"offline"`
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_not_available: Option<Cow<'a, str>>,
+
+  /// The maximum QoS level to be used when receiving and publishing messages.
+  #[serde(default, skip_serializing_if = "MqttQoS::is_default")]
+  pub qos: MqttQoS,
+
+  /// An ID that uniquely identifies this entity. If two entities have the same unique ID,
+  /// Home Assistant will raise an exception.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub unique_id: Option<UniqueId<'a>>,
+}
+
+/// Failure modes shared by every entity's [`Validate`] impl.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntityInvalidity {
+  Topic(<Topic<'static> as Validate>::Invalidity),
+  Template(<Template<'static> as Validate>::Invalidity),
+  Payload(<Payload<'static> as Validate>::Invalidity),
+  /// Both the `availability` list and `availability_topic` were set; Home Assistant
+  /// only allows one form of availability tracking per entity.
+  AmbiguousAvailability,
+}
+
+impl<'a> Validate for Entity<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .invalidate_if(
+        availability::is_ambiguous(&self.availability, self.availability_topic.as_ref()),
+        EntityInvalidity::AmbiguousAvailability,
+      )
+      .validate_with_opt(&self.availability_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.availability_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.json_attributes_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.json_attributes_template, EntityInvalidity::Template)
+      .into()
+  }
+}
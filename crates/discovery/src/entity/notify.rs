@@ -0,0 +1,37 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+
+/// The mqtt notify platform lets you send an MQTT message as a notification service.
+/// Unlike most other entities, notify has no state: each call publishes a single
+/// message to `command_topic` and nothing is subscribed to in return.
+///
+/// See: <https://www.home-assistant.io/integrations/notify.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notify<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// The MQTT topic to publish the notification payload to.
+  #[serde(borrow)]
+  pub command_topic: Topic<'a>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+}
+
+impl<'a> Validate for Notify<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with(&self.command_topic, EntityInvalidity::Topic)
+      .into()
+  }
+}
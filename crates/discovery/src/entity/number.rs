@@ -0,0 +1,103 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  device_class::DeviceClass, template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The display mode of a [`Number`] entity in the frontend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberMode {
+  Auto,
+  Box,
+  Slider,
+}
+
+impl Default for NumberMode {
+  fn default() -> Self {
+    Self::Auto
+  }
+}
+
+impl NumberMode {
+  pub fn is_default(&self) -> bool {
+    matches!(self, Self::Auto)
+  }
+}
+
+/// The mqtt number platform lets you control an MQTT value that represents a range.
+///
+/// See: <https://www.home-assistant.io/integrations/number.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Number<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// The MQTT topic to publish commands to change the number.
+  #[serde(borrow)]
+  pub command_topic: Topic<'a>,
+
+  /// A template to render the command payload with.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_template: Option<Template<'a>>,
+
+  /// The [type/class][device_class] of the number.
+  ///
+  /// [device_class]: https://www.home-assistant.io/integrations/number/#device-class
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub device_class: Option<DeviceClass>,
+
+  /// Maximum value, also the maximum value on the slider/number box. Defaults to `100`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub max: Option<f32>,
+
+  /// Minimum value, also the minimum value on the slider/number box. Defaults to `1`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub min: Option<f32>,
+
+  /// Control how the number should be displayed in the frontend. Defaults to `auto`.
+  #[serde(default, skip_serializing_if = "NumberMode::is_default")]
+  pub mode: NumberMode,
+
+  /// Flag that defines if the number works in optimistic mode. Defaults to `true`
+  /// if no `state_topic` is defined.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub optimistic: Option<bool>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The MQTT topic subscribed to receive number values.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_topic: Option<Topic<'a>>,
+
+  /// Step value. Smallest acceptable value. Defaults to `1`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub step: Option<f32>,
+
+  /// Defines the unit of measurement of the entity, if any.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub unit_of_measurement: Option<Cow<'a, str>>,
+
+  /// Defines a template to extract the value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub value_template: Option<Template<'a>>,
+}
+
+impl<'a> Validate for Number<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.command_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.value_template, EntityInvalidity::Template)
+      .into()
+  }
+}
@@ -0,0 +1,61 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The mqtt select platform lets you select an option from a list via MQTT.
+///
+/// See: <https://www.home-assistant.io/integrations/select.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Select<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// The MQTT topic to publish commands to change the selected option.
+  #[serde(borrow)]
+  pub command_topic: Topic<'a>,
+
+  /// A template to render the command payload with.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_template: Option<Template<'a>>,
+
+  /// Flag that defines if the select works in optimistic mode. Defaults to `true`
+  /// if no `state_topic` is defined.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub optimistic: Option<bool>,
+
+  /// List of options that can be selected. An empty list or a list with a single item
+  /// is not allowed.
+  #[serde(borrow, default, skip_serializing_if = "<[Cow<str>]>::is_empty")]
+  pub options: Cow<'a, [Cow<'a, str>]>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The MQTT topic subscribed to receive the selected option updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_topic: Option<Topic<'a>>,
+
+  /// Defines a template to extract the selected option.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub value_template: Option<Template<'a>>,
+}
+
+impl<'a> Validate for Select<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.command_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.value_template, EntityInvalidity::Template)
+      .into()
+  }
+}
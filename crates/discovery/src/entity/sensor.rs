@@ -1,8 +1,10 @@
 use crate::{
-  availability::{Availability, AvailabilityMode}, device::Device,
-  device_class::DeviceClass, entity_category::EntityCategory, icon::Icon, name::Name, qos::MqttQoS,
+  availability::{self, Availability, AvailabilityMode}, device::Device,
+  device_class::DeviceClass, entity::EntityInvalidity, entity_category::EntityCategory,
+  exts::ValidateContextExt, icon::Icon, name::Name, qos::MqttQoS,
   state_class::StateClass, template::Template, topic::Topic, unique_id::UniqueId,
 };
+use semval::{context::Context, Validate};
 use serde::{Deserialize, Serialize};
 use std::{borrow::Cow, num::NonZeroU32};
 
@@ -47,6 +49,12 @@ pub struct Sensor<'a> {
   #[serde(default, skip_serializing_if = "DeviceClass::is_none")]
   pub device_class: DeviceClass,
 
+  /// The encoding of the payloads received and published messages will be
+  /// published with. Set to `""` to disable decoding of incoming payloads as
+  /// UTF-8, passing them through as raw/unencoded bytes instead. Defaults to `"utf-8"`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub encoding: Option<Cow<'a, str>>,
+
   /// Flag which defines if the entity should be enabled when first added.
   /// Defaults to `true`.
   #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -142,3 +150,23 @@ pub struct Sensor<'a> {
   #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
   pub value_template: Option<Template<'a>>,
 }
+
+impl<'a> Validate for Sensor<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .invalidate_if(
+        availability::is_ambiguous(&self.availability, self.availability_topic.as_ref()),
+        EntityInvalidity::AmbiguousAvailability,
+      )
+      .validate_with_opt(&self.availability_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.availability_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.json_attributes_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.json_attributes_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.last_reset_value_template, EntityInvalidity::Template)
+      .validate_with(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.value_template, EntityInvalidity::Template)
+      .into()
+  }
+}
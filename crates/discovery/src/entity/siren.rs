@@ -0,0 +1,89 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  payload::Payload, template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The mqtt siren platform lets you control your MQTT enabled sirens.
+///
+/// See: <https://www.home-assistant.io/integrations/siren.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Siren<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// Flag that defines if the entity works in optimistic mode. Defaults to `true`
+  /// if no `state_topic` is defined, else `false`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub optimistic: Option<bool>,
+
+  /// A list of available tones the siren supports. When configured, the `available_tones`
+  /// payload key can be used to select one by name.
+  #[serde(borrow, default, skip_serializing_if = "<[Cow<str>]>::is_empty")]
+  pub available_tones: Cow<'a, [Cow<'a, str>]>,
+
+  /// The MQTT topic to publish commands to change the siren state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_topic: Option<Topic<'a>>,
+
+  /// A template to render the command payload with.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_template: Option<Template<'a>>,
+
+  /// The payload that represents the off state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_off: Option<Payload<'a>>,
+  /// The payload that represents the on state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_on: Option<Payload<'a>>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The MQTT topic subscribed to receive state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_topic: Option<Topic<'a>>,
+  /// The payload that represents the off state, to match against `state_topic`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_off: Option<Payload<'a>>,
+  /// The payload that represents the on state, to match against `state_topic`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_on: Option<Payload<'a>>,
+  /// Defines a template to extract the state.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_value_template: Option<Template<'a>>,
+
+  /// Defines a template to generate the payload to send to `command_topic` when
+  /// the siren is turned off with parameters.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_off_template: Option<Template<'a>>,
+
+  /// Defines a template to generate the payload to send to `command_topic` when
+  /// the siren is turned on with parameters.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_on_template: Option<Template<'a>>,
+}
+
+impl<'a> Validate for Siren<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with_opt(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.command_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.command_off_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.command_on_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.payload_on, EntityInvalidity::Payload)
+      .validate_with_opt(&self.payload_off, EntityInvalidity::Payload)
+      .validate_with_opt(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.state_on, EntityInvalidity::Payload)
+      .validate_with_opt(&self.state_off, EntityInvalidity::Payload)
+      .validate_with_opt(&self.state_value_template, EntityInvalidity::Template)
+      .into()
+  }
+}
@@ -40,6 +40,37 @@ pub struct Switch<'a> {
 	pub value_template: Option<Template<'a>>,
 }
 
+impl<'a> Switch<'a> {
+  /// Whether this switch runs in Home Assistant's optimistic mode: forced by
+  /// an explicit `optimistic`, or otherwise implied by the absence of a
+  /// `state_topic` to wait for confirmation on.
+  pub fn is_optimistic(&self) -> bool {
+    self.optimistic.unwrap_or_else(|| self.state_topic.is_none())
+  }
+
+  /// In optimistic mode, maps a command payload received on `command_topic` to
+  /// the state payload that should be echoed back immediately, through
+  /// `state_on`/`state_off` when set. Falls back to HA's default `"ON"`/`"OFF"`
+  /// payloads when `payload_on`/`payload_off` aren't configured. Returns `None`
+  /// when not optimistic, or when `command` doesn't match either payload.
+  pub fn optimistic_state_for_command<'b>(&'b self, command: &str) -> Option<&'b str> {
+    if !self.is_optimistic() {
+      return None;
+    }
+
+    let on = self.payload_on.as_ref().map(AsRef::as_ref).unwrap_or("ON");
+    let off = self.payload_off.as_ref().map(AsRef::as_ref).unwrap_or("OFF");
+
+    if command == on {
+      Some(self.state_on.as_ref().map(AsRef::as_ref).unwrap_or(on))
+    } else if command == off {
+      Some(self.state_off.as_ref().map(AsRef::as_ref).unwrap_or(off))
+    } else {
+      None
+    }
+  }
+}
+
 impl<'a> Validate for Switch<'a> {
   type Invalidity = EntityInvalidity;
 
@@ -56,3 +87,66 @@ impl<'a> Validate for Switch<'a> {
       .into()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_state_topic_is_optimistic_by_default() {
+    assert!(Switch::default().is_optimistic());
+  }
+
+  #[test]
+  fn state_topic_without_override_is_not_optimistic() {
+    let switch = Switch { state_topic: Some("switch/state".into()), ..Switch::default() };
+    assert!(!switch.is_optimistic());
+  }
+
+  #[test]
+  fn forced_optimistic_overrides_state_topic() {
+    let switch = Switch {
+      state_topic: Some("switch/state".into()),
+      optimistic: Some(true),
+      ..Switch::default()
+    };
+    assert!(switch.is_optimistic());
+  }
+
+  #[test]
+  fn explicit_false_forces_non_optimistic_without_state_topic() {
+    let switch = Switch { optimistic: Some(false), ..Switch::default() };
+    assert!(!switch.is_optimistic());
+  }
+
+  #[test]
+  fn echoes_default_on_off_payloads() {
+    let switch = Switch::default();
+    assert_eq!(switch.optimistic_state_for_command("ON"), Some("ON"));
+    assert_eq!(switch.optimistic_state_for_command("OFF"), Some("OFF"));
+    assert_eq!(switch.optimistic_state_for_command("TOGGLE"), None);
+  }
+
+  #[test]
+  fn echoes_through_state_on_off_mapping() {
+    let switch = Switch {
+      payload_on: Some("1".into()),
+      payload_off: Some("0".into()),
+      state_on: Some("online".into()),
+      state_off: Some("offline".into()),
+      ..Switch::default()
+    };
+    assert_eq!(switch.optimistic_state_for_command("1"), Some("online"));
+    assert_eq!(switch.optimistic_state_for_command("0"), Some("offline"));
+  }
+
+  #[test]
+  fn non_optimistic_switch_never_echoes() {
+    let switch = Switch {
+      state_topic: Some("switch/state".into()),
+      payload_on: Some("ON".into()),
+      ..Switch::default()
+    };
+    assert_eq!(switch.optimistic_state_for_command("ON"), None);
+  }
+}
@@ -0,0 +1,88 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The display mode of a [`Text`] entity in the frontend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextMode {
+  Text,
+  Password,
+}
+
+impl Default for TextMode {
+  fn default() -> Self {
+    Self::Text
+  }
+}
+
+impl TextMode {
+  pub fn is_default(&self) -> bool {
+    matches!(self, Self::Text)
+  }
+}
+
+/// The mqtt text platform lets you send text data via an MQTT topic and show text received
+/// from a topic in the frontend.
+///
+/// See: <https://www.home-assistant.io/integrations/text.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Text<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// The MQTT topic to publish commands to change the text.
+  #[serde(borrow)]
+  pub command_topic: Topic<'a>,
+
+  /// A template to render the command payload with.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_template: Option<Template<'a>>,
+
+  /// The maximum size of a text being set or received (maximum is `255`).
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub max: Option<u16>,
+
+  /// The minimum size of a text being set or received.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub min: Option<u16>,
+
+  /// The mode of the text entity.
+  #[serde(default, skip_serializing_if = "TextMode::is_default")]
+  pub mode: TextMode,
+
+  /// A valid regular expression the text being set or received must match with.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub pattern: Option<Cow<'a, str>>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The MQTT topic subscribed to receive text state updates.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub state_topic: Option<Topic<'a>>,
+
+  /// Defines a template to extract the text value.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub value_template: Option<Template<'a>>,
+}
+
+impl<'a> Validate for Text<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.command_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.value_template, EntityInvalidity::Template)
+      .into()
+  }
+}
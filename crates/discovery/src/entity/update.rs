@@ -0,0 +1,70 @@
+use crate::{
+  exts::ValidateContextExt,
+  entity::{Entity, EntityInvalidity},
+  device_class::DeviceClass, payload::Payload, template::Template, topic::Topic,
+};
+use semval::{context::Context, Validate};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The mqtt update platform lets you expose the firmware/software update state
+/// of a device as an MQTT-backed entity.
+///
+/// See: <https://www.home-assistant.io/integrations/update.mqtt/>
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Update<'a> {
+  #[serde(borrow, flatten)]
+  pub entity: Entity<'a>,
+
+  /// The MQTT topic to publish commands to install an update.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub command_topic: Option<Topic<'a>>,
+
+  /// The [type/class][device_class] of the update.
+  ///
+  /// [device_class]: https://www.home-assistant.io/integrations/update/#device-class
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub device_class: Option<DeviceClass>,
+
+  /// Summary of the release notes or changelog, supports Markdown.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub release_summary: Option<Cow<'a, str>>,
+  /// URL to the full release notes of the latest version available.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub release_url: Option<Cow<'a, str>>,
+
+  /// If the published message should have the retain flag on or not.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub retain: Option<bool>,
+
+  /// The MQTT topic subscribed to receive update state as a JSON payload,
+  /// minimally containing `installed_version` and `latest_version`.
+  #[serde(borrow)]
+  pub state_topic: Topic<'a>,
+  /// Defines a template to extract the JSON dictionary from messages received
+  /// on `state_topic`.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub value_template: Option<Template<'a>>,
+
+  /// Payload to send to `command_topic` to start installing the update.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub payload_install: Option<Payload<'a>>,
+
+  /// Title of the software, displayed in the frontend update dialog.
+  #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+  pub title: Option<Cow<'a, str>>,
+}
+
+impl<'a> Validate for Update<'a> {
+  type Invalidity = EntityInvalidity;
+
+  fn validate(&self) -> semval::Result<Self::Invalidity> {
+    Context::new()
+      .validate_with(&self.entity, |v| v)
+      .validate_with_opt(&self.command_topic, EntityInvalidity::Topic)
+      .validate_with(&self.state_topic, EntityInvalidity::Topic)
+      .validate_with_opt(&self.value_template, EntityInvalidity::Template)
+      .validate_with_opt(&self.payload_install, EntityInvalidity::Payload)
+      .into()
+  }
+}
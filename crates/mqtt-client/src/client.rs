@@ -0,0 +1,58 @@
+use crate::{error::ClientError, router::Router, tracking::SwitchTracking};
+use hass_mqtt_provider::QosLevel;
+use std::sync::{Arc, Mutex};
+
+/// An incoming message delivered on a topic this client has subscribed to.
+#[derive(Debug, Clone)]
+pub struct Message {
+  pub topic: String,
+  pub payload: Vec<u8>,
+}
+
+/// Anything capable of publishing raw MQTT payloads. Implemented by whichever
+/// `hass_mqtt_provider` backend (rumqttc, paho, ...) a [`HassMqttClient`] was built with.
+pub trait MqttPublisher: Send + Sync {
+  fn publish(&self, topic: &str, payload: &[u8], qos: QosLevel, retain: bool) -> Result<(), ClientError>;
+}
+
+/// A cloneable handle to a connected Home Assistant MQTT session. Every
+/// [`EntityTopic`](crate::entity::EntityTopic) registered against a client shares its
+/// connection and its [`Router`], so a device's entities all see the same dispatch.
+#[derive(Clone)]
+pub struct HassMqttClient {
+  publisher: Arc<dyn MqttPublisher>,
+  router: Arc<Mutex<Router<'static>>>,
+}
+
+impl HassMqttClient {
+  pub(crate) fn new(publisher: Arc<dyn MqttPublisher>) -> Self {
+    Self { publisher, router: Arc::new(Mutex::new(Router::new())) }
+  }
+
+  /// Publishes `payload` to `topic` at the given QoS.
+  pub fn publish(&self, topic: &str, payload: impl AsRef<[u8]>, qos: QosLevel, retain: bool) -> Result<(), ClientError> {
+    self.publisher.publish(topic, payload.as_ref(), qos, retain)
+  }
+
+  /// Registers a switch's optimistic echo mapping so future commands on
+  /// `command_topic` are answered with a state echo through [`handle_message`](Self::handle_message).
+  pub fn register_switch(&self, command_topic: impl Into<String>, tracking: SwitchTracking<'static>) {
+    self.router.lock().expect("router lock poisoned").register_switch(command_topic, tracking);
+  }
+
+  /// Feeds an incoming message through the [`Router`], publishing an optimistic
+  /// state echo when a registered switch's command matches.
+  pub fn handle_message(&self, message: &Message) -> Result<(), ClientError> {
+    let echo = self.router.lock().expect("router lock poisoned").route(&message.topic, &message.payload);
+
+    if let Some((state_topic, state)) = echo {
+      self.publish(&state_topic, state.as_bytes(), QosLevel::AtLeastOnce, true)?;
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn router(&self) -> Arc<Mutex<Router<'static>>> {
+    self.router.clone()
+  }
+}
@@ -0,0 +1,114 @@
+use crate::{client::HassMqttClient, error::ClientError};
+use hass_mqtt_provider::QosLevel;
+
+/// A handle for publishing to an entity's `command_topic`.
+#[derive(Clone)]
+pub struct CommandTopic {
+  client: HassMqttClient,
+  topic: String,
+  qos: QosLevel,
+  retain: bool,
+}
+
+impl CommandTopic {
+  pub(crate) fn new(client: HassMqttClient, topic: impl Into<String>, qos: QosLevel, retain: bool) -> Self {
+    Self { client, topic: topic.into(), qos, retain }
+  }
+
+  pub fn topic(&self) -> &str {
+    &self.topic
+  }
+
+  /// Publishes `payload` to this entity's command topic. For a stateless entity like
+  /// [`Notify`](hass_mqtt_types::entity::notify::Notify) this is the entire interaction:
+  /// each call is a fire-and-forget message, there is no state to track.
+  pub fn send(&self, payload: impl AsRef<[u8]>) -> Result<(), ClientError> {
+    self.client.publish(&self.topic, payload.as_ref(), self.qos, self.retain)
+  }
+}
+
+/// A handle for publishing an entity's `state_topic`.
+#[derive(Clone)]
+pub struct StateTopic {
+  client: HassMqttClient,
+  topic: String,
+  qos: QosLevel,
+  retain: bool,
+}
+
+impl StateTopic {
+  pub(crate) fn new(client: HassMqttClient, topic: impl Into<String>, qos: QosLevel, retain: bool) -> Self {
+    Self { client, topic: topic.into(), qos, retain }
+  }
+
+  pub fn topic(&self) -> &str {
+    &self.topic
+  }
+
+  /// Publishes `payload` as the entity's current state. `payload` is sent verbatim, so
+  /// a raw-encoded entity (`encoding: Some("")`) can publish arbitrary bytes here; a
+  /// text-encoded entity should go through [`publish_text`](Self::publish_text) instead.
+  pub fn publish(&self, payload: impl AsRef<[u8]>) -> Result<(), ClientError> {
+    self.client.publish(&self.topic, payload.as_ref(), self.qos, self.retain)
+  }
+
+  /// Publishes `state` as UTF-8 text, for the common case of a text-encoded entity
+  /// (`encoding: None` or `Some("utf-8")`).
+  pub fn publish_text(&self, state: &str) -> Result<(), ClientError> {
+    self.publish(state.as_bytes())
+  }
+}
+
+/// A registered entity's command/state topic handles, as applicable to its platform.
+#[derive(Clone, Default)]
+pub struct EntityTopic {
+  pub command: Option<CommandTopic>,
+  pub state: Option<StateTopic>,
+}
+
+/// The result of interpreting a raw payload according to an entity's `encoding` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadEncoding<'a> {
+  /// Decoded as UTF-8 text (the default, and anything but an explicit empty `encoding`).
+  Text(&'a str),
+  /// Passed through undecoded: either `encoding` was explicitly set to `""`, or the
+  /// payload wasn't valid UTF-8 to begin with.
+  Raw(&'a [u8]),
+}
+
+/// Interprets `payload` according to an entity's `encoding` field (`None` and `Some("utf-8")`
+/// decode as UTF-8 text; `Some("")` means raw/unencoded binary, so no decoding is attempted),
+/// so devices sending non-text payloads round-trip as bytes instead of being forced through
+/// lossy UTF-8 handling.
+pub fn decode_payload<'a>(encoding: Option<&str>, payload: &'a [u8]) -> PayloadEncoding<'a> {
+  if encoding == Some("") {
+    return PayloadEncoding::Raw(payload);
+  }
+
+  match std::str::from_utf8(payload) {
+    Ok(text) => PayloadEncoding::Text(text),
+    Err(_) => PayloadEncoding::Raw(payload),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_encoding_decodes_utf8_text() {
+    assert_eq!(decode_payload(None, b"ON"), PayloadEncoding::Text("ON"));
+    assert_eq!(decode_payload(Some("utf-8"), b"ON"), PayloadEncoding::Text("ON"));
+  }
+
+  #[test]
+  fn empty_encoding_forces_raw() {
+    assert_eq!(decode_payload(Some(""), b"ON"), PayloadEncoding::Raw(b"ON"));
+  }
+
+  #[test]
+  fn invalid_utf8_falls_back_to_raw() {
+    let payload = &[0xff, 0x00];
+    assert_eq!(decode_payload(None, payload), PayloadEncoding::Raw(payload));
+  }
+}
@@ -0,0 +1,38 @@
+use crate::options::{MqttOptionsError, MqttPersistenceError};
+use std::fmt;
+
+/// Errors surfaced while publishing to, or dispatching messages through, a
+/// [`HassMqttClient`](crate::client::HassMqttClient).
+#[derive(Debug)]
+pub enum ClientError {
+  /// The underlying MQTT connection rejected or failed to send a message.
+  Publish(String),
+  /// The options used to configure the client were invalid.
+  Options(MqttOptionsError),
+  /// A persistent session file could not be read or written.
+  Persistence(MqttPersistenceError),
+}
+
+impl fmt::Display for ClientError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Publish(reason) => write!(f, "failed to publish: {reason}"),
+      Self::Options(err) => write!(f, "invalid mqtt options: {err}"),
+      Self::Persistence(err) => write!(f, "persistence error: {err}"),
+    }
+  }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<MqttOptionsError> for ClientError {
+  fn from(err: MqttOptionsError) -> Self {
+    Self::Options(err)
+  }
+}
+
+impl From<MqttPersistenceError> for ClientError {
+  fn from(err: MqttPersistenceError) -> Self {
+    Self::Persistence(err)
+  }
+}
@@ -0,0 +1,135 @@
+use crate::topics;
+use hass_mqtt_types::device::Device;
+use std::{fmt, path::PathBuf};
+
+/// The payload published (retained) on a device's shared availability topic when it
+/// connects.
+pub const BIRTH_PAYLOAD: &str = "online";
+/// The payload set as the broker-held last will on a device's shared availability
+/// topic, to be published automatically if the connection drops uncleanly.
+pub const LAST_WILL_PAYLOAD: &str = "offline";
+
+/// A device registered with a [`HassMqttOptions`], grouping every entity it owns
+/// under a shared discovery `node_id` and a shared availability topic.
+#[derive(Debug, Clone)]
+pub struct DeviceOptions<'a> {
+  device: Device<'a>,
+  node_id: String,
+}
+
+impl<'a> DeviceOptions<'a> {
+  pub fn new(device: Device<'a>, node_id: impl Into<String>) -> Self {
+    Self { device, node_id: node_id.into() }
+  }
+
+  pub fn device(&self) -> &Device<'a> {
+    &self.device
+  }
+
+  pub fn node_id(&self) -> &str {
+    &self.node_id
+  }
+
+  /// The grouped discovery config topic for one of this device's entities.
+  pub fn discovery_topic(&self, discovery_prefix: &str, component: &str, object_id: &str) -> String {
+    topics::device_discovery_topic(discovery_prefix, component, &self.node_id, object_id)
+  }
+
+  /// The single topic this device's entities all share for birth/LWT availability,
+  /// under the regular state-topic namespace rather than the discovery prefix.
+  pub fn availability_topic(&self, base_topic: &str) -> String {
+    topics::device_availability_topic(base_topic, &self.node_id)
+  }
+}
+
+/// Options used to configure a [`HassMqttClient`](crate::client::HassMqttClient):
+/// the discovery and state topic namespaces, and the devices registered under them.
+#[derive(Debug, Clone)]
+pub struct HassMqttOptions<'a> {
+  discovery_prefix: String,
+  base_topic: String,
+  devices: Vec<DeviceOptions<'a>>,
+  persistence_path: Option<PathBuf>,
+}
+
+impl<'a> HassMqttOptions<'a> {
+  pub fn new(discovery_prefix: impl Into<String>, base_topic: impl Into<String>) -> Self {
+    Self {
+      discovery_prefix: discovery_prefix.into(),
+      base_topic: base_topic.into(),
+      devices: Vec::new(),
+      persistence_path: None,
+    }
+  }
+
+  pub fn discovery_prefix(&self) -> &str {
+    &self.discovery_prefix
+  }
+
+  pub fn base_topic(&self) -> &str {
+    &self.base_topic
+  }
+
+  pub fn persistence_path(&self) -> Option<&std::path::Path> {
+    self.persistence_path.as_deref()
+  }
+
+  pub fn with_persistence_path(mut self, path: impl Into<PathBuf>) -> Self {
+    self.persistence_path = Some(path.into());
+    self
+  }
+
+  /// Registers a device, returning its [`DeviceOptions`] so callers can derive
+  /// discovery and availability topics for the entities it owns.
+  pub fn device(&mut self, device: Device<'a>, node_id: impl Into<String>) -> &DeviceOptions<'a> {
+    self.devices.push(DeviceOptions::new(device, node_id));
+    self.devices.last().expect("just pushed")
+  }
+
+  pub fn devices(&self) -> &[DeviceOptions<'a>] {
+    &self.devices
+  }
+
+  /// The `(topic, birth_payload, last_will_payload)` triples every registered
+  /// device's availability topic should be primed with on connect.
+  pub fn last_will_topics(&self) -> Vec<(String, &'static str, &'static str)> {
+    self
+      .devices
+      .iter()
+      .map(|device| (device.availability_topic(&self.base_topic), BIRTH_PAYLOAD, LAST_WILL_PAYLOAD))
+      .collect()
+  }
+}
+
+/// Errors in the [`HassMqttOptions`] used to configure a client.
+#[derive(Debug)]
+pub enum MqttOptionsError {
+  /// `discovery_prefix` or `base_topic` was empty.
+  EmptyTopic(&'static str),
+}
+
+impl fmt::Display for MqttOptionsError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::EmptyTopic(field) => write!(f, "{field} must not be empty"),
+    }
+  }
+}
+
+impl std::error::Error for MqttOptionsError {}
+
+/// Errors reading or writing a client's persistent session file.
+#[derive(Debug)]
+pub enum MqttPersistenceError {
+  Io(PathBuf, std::io::Error),
+}
+
+impl fmt::Display for MqttPersistenceError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Io(path, err) => write!(f, "failed to access persistence file {}: {err}", path.display()),
+    }
+  }
+}
+
+impl std::error::Error for MqttPersistenceError {}
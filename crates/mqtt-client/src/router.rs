@@ -0,0 +1,35 @@
+use crate::tracking::SwitchTracking;
+use std::collections::HashMap;
+
+/// Dispatches incoming messages to registered entities that need more than a plain
+/// subscription — currently, optimistic command-to-state echoing for switch-like
+/// entities. Other platforms publish and subscribe directly through their
+/// [`EntityTopic`](crate::entity::EntityTopic) without going through the router.
+#[derive(Default)]
+pub struct Router<'a> {
+  switches: HashMap<String, SwitchTracking<'a>>,
+}
+
+impl<'a> Router<'a> {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Registers a switch's optimistic echo mapping against the `command_topic`
+  /// it will receive commands on.
+  pub fn register_switch(&mut self, command_topic: impl Into<String>, tracking: SwitchTracking<'a>) {
+    self.switches.insert(command_topic.into(), tracking);
+  }
+
+  pub fn unregister_switch(&mut self, command_topic: &str) {
+    self.switches.remove(command_topic);
+  }
+
+  /// Looks up the optimistic echo (state topic, state payload) for a command
+  /// received on `topic`, if any entity is registered for it.
+  pub fn route(&self, topic: &str, payload: &[u8]) -> Option<(String, String)> {
+    let tracking = self.switches.get(topic)?;
+    let (state_topic, state) = tracking.echo_for_command(payload)?;
+    Some((state_topic.to_owned(), state.to_owned()))
+  }
+}
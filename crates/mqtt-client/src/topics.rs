@@ -0,0 +1,16 @@
+/// Builds the discovery config topic for an entity grouped under a device, following
+/// Home Assistant's `<discovery_prefix>/<component>/<node_id>/<object_id>/config` layout.
+/// `node_id` is typically the device's own unique id, shared by every entity it owns, so
+/// that HA groups them together instead of scanning `<discovery_prefix>/<component>/+/config`.
+pub fn device_discovery_topic(discovery_prefix: &str, component: &str, node_id: &str, object_id: &str) -> String {
+  format!("{discovery_prefix}/{component}/{node_id}/{object_id}/config")
+}
+
+/// Builds the single availability topic a device's entities should all share, so one
+/// birth/LWT pair governs every entity's online/offline state instead of each entity
+/// tracking its own `availability_topic`. `base_topic` is the regular MQTT state-topic
+/// namespace the device publishes under — distinct from `discovery_prefix`, which is
+/// reserved for `.../config` discovery payloads and must not leak into a runtime topic.
+pub fn device_availability_topic(base_topic: &str, node_id: &str) -> String {
+  format!("{base_topic}/{node_id}/availability")
+}
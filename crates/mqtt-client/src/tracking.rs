@@ -0,0 +1,30 @@
+use crate::entity::{decode_payload, PayloadEncoding};
+use hass_mqtt_types::entity::switch::Switch;
+
+/// A registered switch's config plus the state topic its optimistic echoes are
+/// published on, so the [`Router`](crate::router::Router) can turn an incoming
+/// command straight into an echoed state without the caller re-deriving either.
+#[derive(Clone)]
+pub struct SwitchTracking<'a> {
+  switch: Switch<'a>,
+  state_topic: String,
+}
+
+impl<'a> SwitchTracking<'a> {
+  pub fn new(switch: Switch<'a>, state_topic: impl Into<String>) -> Self {
+    Self { switch, state_topic: state_topic.into() }
+  }
+
+  /// Maps a raw command payload to the `(state_topic, state_payload)` to echo,
+  /// honoring the switch's `encoding` field. Binary (non-UTF-8, or explicitly
+  /// raw-encoded) commands never match, since `payload_on`/`payload_off` are
+  /// always plain text.
+  pub fn echo_for_command(&self, command: &[u8]) -> Option<(&str, &str)> {
+    let command = match decode_payload(self.switch.entity.encoding.as_deref(), command) {
+      PayloadEncoding::Text(text) => text,
+      PayloadEncoding::Raw(_) => return None,
+    };
+
+    self.switch.optimistic_state_for_command(command).map(|state| (self.state_topic.as_str(), state))
+  }
+}